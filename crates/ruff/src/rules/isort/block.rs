@@ -1,5 +1,5 @@
 use ruff_text_size::{TextRange, TextSize};
-use rustpython_parser::ast::{self, Excepthandler, MatchCase, Ranged, Stmt};
+use rustpython_parser::ast::{self, Excepthandler, Expr, MatchCase, Ranged, Stmt};
 
 use ruff_python_ast::source_code::Locator;
 use ruff_python_ast::statement_visitor::StatementVisitor;
@@ -10,11 +10,48 @@ use crate::rules::isort::helpers;
 /// A block of imports within a Python module.
 #[derive(Debug, Default)]
 pub(crate) struct Block<'a> {
-    pub(crate) nested: bool,
+    pub(crate) nesting: Nesting,
     pub(crate) imports: Vec<&'a Stmt>,
     pub(crate) trailer: Option<Trailer>,
 }
 
+/// Where a [`Block`] sits relative to module-level code.
+///
+/// Most nested scopes (function bodies, loops, `with` statements, ...) are left
+/// entirely untouched by isort, but a couple of shapes are common enough, and
+/// unambiguous enough, that we still want to sort and merge imports inside of them.
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq)]
+pub(crate) enum Nesting {
+    /// The block lives at the top level of the module.
+    #[default]
+    TopLevel,
+    /// The block lives inside a recognized import guard: an `if TYPE_CHECKING:` body,
+    /// or a `try`/`except` suite made up exclusively of imports. These are treated as
+    /// first-class, sortable blocks, just without the blank-line trailer enforcement
+    /// that only makes sense at module scope.
+    Guarded(GuardKind),
+    /// The block lives inside some other nested scope. isort leaves these alone.
+    Opaque,
+}
+
+impl Nesting {
+    /// Whether imports in a block with this nesting should still be sorted (and
+    /// merged), as opposed to left exactly as written.
+    pub(crate) fn is_sortable(self) -> bool {
+        matches!(self, Nesting::TopLevel | Nesting::Guarded(_))
+    }
+}
+
+/// The kind of import guard that a [`Nesting::Guarded`] block was found inside of.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub(crate) enum GuardKind {
+    /// `if TYPE_CHECKING:` (or `if typing.TYPE_CHECKING:`).
+    TypeChecking,
+    /// A `try`/`except` (or `try`/`except*`) suite whose statements are exclusively
+    /// imports, e.g. `try: import ujson as json / except ImportError: import json`.
+    TryExcept,
+}
+
 /// The type of trailer that should follow an import block.
 #[derive(Debug, Copy, Clone)]
 pub(crate) enum Trailer {
@@ -30,7 +67,7 @@ pub(crate) struct BlockBuilder<'a> {
     blocks: Vec<Block<'a>>,
     splits: &'a [TextSize],
     exclusions: &'a [TextRange],
-    nested: bool,
+    nesting: Nesting,
 }
 
 impl<'a> BlockBuilder<'a> {
@@ -45,14 +82,14 @@ impl<'a> BlockBuilder<'a> {
             blocks: vec![Block::default()],
             splits: &directives.splits,
             exclusions: &directives.exclusions,
-            nested: false,
+            nesting: Nesting::TopLevel,
         }
     }
 
     fn track_import(&mut self, stmt: &'a Stmt) {
         let index = self.blocks.len() - 1;
         self.blocks[index].imports.push(stmt);
-        self.blocks[index].nested = self.nested;
+        self.blocks[index].nesting = self.nesting;
     }
 
     fn trailer_for(&self, stmt: &'a Stmt) -> Option<Trailer> {
@@ -63,7 +100,10 @@ impl<'a> BlockBuilder<'a> {
         }
 
         // Similar to isort, avoid enforcing any newline behaviors in nested blocks.
-        if self.nested {
+        // This also covers guarded blocks (`TYPE_CHECKING`, `try`/`except`): they're
+        // sortable, but the two-blank-line convention only makes sense at module
+        // scope.
+        if self.nesting != Nesting::TopLevel {
             return None;
         }
 
@@ -114,6 +154,28 @@ impl<'a> BlockBuilder<'a> {
     }
 }
 
+/// Whether `test` is a bare `TYPE_CHECKING` name, or a `typing.TYPE_CHECKING`
+/// attribute access.
+fn is_type_checking_test(test: &Expr) -> bool {
+    match test {
+        Expr::Name(ast::ExprName { id, .. }) => id == "TYPE_CHECKING",
+        Expr::Attribute(ast::ExprAttribute { attr, value, .. }) => {
+            attr == "TYPE_CHECKING"
+                && matches!(value.as_ref(), Expr::Name(ast::ExprName { id, .. }) if id == "typing")
+        }
+        _ => false,
+    }
+}
+
+/// Whether every statement in `body` is an import, making it eligible to be treated as
+/// a guarded import block (e.g. a `try`/`except ImportError` suite).
+fn is_import_only_suite(body: &[Stmt]) -> bool {
+    !body.is_empty()
+        && body
+            .iter()
+            .all(|stmt| matches!(stmt, Stmt::Import(_) | Stmt::ImportFrom(_)))
+}
+
 impl<'a, 'b> StatementVisitor<'b> for BlockBuilder<'a>
 where
     'b: 'a,
@@ -147,9 +209,11 @@ where
             self.finalize(self.trailer_for(stmt));
         }
 
-        // Track scope.
-        let prev_nested = self.nested;
-        self.nested = true;
+        // Track scope. Most compound statements put us in an opaque, unsortable
+        // region; a couple of recognized guard shapes (handled below) instead put us
+        // in a guarded-but-sortable one.
+        let prev_nesting = self.nesting;
+        self.nesting = Nesting::Opaque;
         match stmt {
             Stmt::FunctionDef(ast::StmtFunctionDef { body, .. }) => {
                 for stmt in body {
@@ -202,12 +266,23 @@ where
                 }
                 self.finalize(None);
             }
-            Stmt::If(ast::StmtIf { body, orelse, .. }) => {
+            Stmt::If(ast::StmtIf {
+                test, body, orelse, ..
+            }) => {
+                // `if TYPE_CHECKING:` (and `if typing.TYPE_CHECKING:`) bodies are
+                // common enough, and unambiguous enough, that we still sort and merge
+                // imports inside of them.
+                self.nesting = if is_type_checking_test(test) {
+                    Nesting::Guarded(GuardKind::TypeChecking)
+                } else {
+                    Nesting::Opaque
+                };
                 for stmt in body {
                     self.visit_stmt(stmt);
                 }
                 self.finalize(None);
 
+                self.nesting = Nesting::Opaque;
                 for stmt in orelse {
                     self.visit_stmt(stmt);
                 }
@@ -248,11 +323,21 @@ where
                     self.visit_excepthandler(excepthandler);
                 }
 
+                // A `try` body made up exclusively of imports (e.g. `try: import
+                // ujson as json`) is a guard, not an opaque nested scope. If the body
+                // mixes imports with other statements, keep the conservative
+                // don't-touch behavior.
+                self.nesting = if is_import_only_suite(body) {
+                    Nesting::Guarded(GuardKind::TryExcept)
+                } else {
+                    Nesting::Opaque
+                };
                 for stmt in body {
                     self.visit_stmt(stmt);
                 }
                 self.finalize(None);
 
+                self.nesting = Nesting::Opaque;
                 for stmt in orelse {
                     self.visit_stmt(stmt);
                 }
@@ -265,21 +350,28 @@ where
             }
             _ => {}
         }
-        self.nested = prev_nested;
+        self.nesting = prev_nesting;
     }
 
     fn visit_excepthandler(&mut self, excepthandler: &'b Excepthandler) {
-        let prev_nested = self.nested;
-        self.nested = true;
+        let prev_nesting = self.nesting;
 
         let Excepthandler::ExceptHandler(ast::ExcepthandlerExceptHandler { body, .. }) =
             excepthandler;
+        // As with the `try` body, an `except` suite made up exclusively of imports
+        // (e.g. `except ImportError: import json`) is a guard, not an opaque nested
+        // scope.
+        self.nesting = if is_import_only_suite(body) {
+            Nesting::Guarded(GuardKind::TryExcept)
+        } else {
+            Nesting::Opaque
+        };
         for stmt in body {
             self.visit_stmt(stmt);
         }
         self.finalize(None);
 
-        self.nested = prev_nested;
+        self.nesting = prev_nesting;
     }
 
     fn visit_match_case(&mut self, match_case: &'b MatchCase) {
@@ -288,4 +380,62 @@ where
         }
         self.finalize(None);
     }
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn if_test(source: &str) -> Expr {
+        let program = rustpython_parser::parse_program(source, "<filename>").unwrap();
+        let Stmt::If(ast::StmtIf { test, .. }) = &program[0] else {
+            panic!("expected an `if` statement");
+        };
+        (**test).clone()
+    }
+
+    #[test]
+    fn recognizes_bare_type_checking() {
+        let test = if_test("if TYPE_CHECKING:\n    import a\n");
+        assert!(is_type_checking_test(&test));
+    }
+
+    #[test]
+    fn recognizes_qualified_type_checking() {
+        let test = if_test("if typing.TYPE_CHECKING:\n    import a\n");
+        assert!(is_type_checking_test(&test));
+    }
+
+    #[test]
+    fn rejects_unrelated_conditions() {
+        let test = if_test("if DEBUG:\n    import a\n");
+        assert!(!is_type_checking_test(&test));
+    }
+
+    fn try_body(source: &str) -> Vec<Stmt> {
+        let mut program = rustpython_parser::parse_program(source, "<filename>").unwrap();
+        let Stmt::Try(ast::StmtTry { body, .. }) = program.remove(0) else {
+            panic!("expected a `try` statement");
+        };
+        body
+    }
+
+    #[test]
+    fn recognizes_import_only_try_body() {
+        let body = try_body("try:\n    import ujson as json\nexcept ImportError:\n    pass\n");
+        assert!(is_import_only_suite(&body));
+    }
+
+    #[test]
+    fn rejects_try_body_mixing_imports_and_other_statements() {
+        let body = try_body(
+            "try:\n    import ujson as json\n    print('loaded')\nexcept ImportError:\n    pass\n",
+        );
+        assert!(!is_import_only_suite(&body));
+    }
+
+    #[test]
+    fn rejects_empty_suite() {
+        assert!(!is_import_only_suite(&[]));
+    }
+}
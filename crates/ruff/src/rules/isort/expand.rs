@@ -0,0 +1,82 @@
+use std::path::Path;
+
+use rustpython_parser::ast::{self, Stmt};
+
+use crate::rules::isort::block::Block;
+use crate::rules::isort::resolve::{public_surface, ModuleResolver};
+
+/// A `from module import *` rewritten into its explicit, sorted member list.
+#[derive(Debug)]
+pub(crate) struct ExpandedGlobImport<'a> {
+    pub(crate) statement: &'a Stmt,
+    pub(crate) members: Vec<String>,
+}
+
+/// Expand every `from module import *` in `block` into an explicit member list,
+/// mirroring rust-analyzer's `expand_glob_import` assist.
+///
+/// This performs cross-file I/O (resolving `module` to a file, parsing it, and
+/// computing its public surface) that the rest of isort never needs, so it's gated
+/// behind `enabled` and expected to remain opt-in.
+///
+/// `unresolved_names` is the set of names referenced in the current module that
+/// aren't bound by anything else, and so must come from one of its glob imports.
+/// Expansion only proceeds when every such name is accounted for by the target
+/// module's computed public surface; otherwise, blindly expanding would silently drop
+/// a re-export that the current module actually relies on.
+pub(crate) fn expand_glob_imports<'a>(
+    block: &Block<'a>,
+    current_file: &Path,
+    resolver: &ModuleResolver,
+    unresolved_names: &[String],
+    enabled: bool,
+) -> Vec<ExpandedGlobImport<'a>> {
+    if !enabled || !block.nesting.is_sortable() {
+        return Vec::new();
+    }
+
+    let mut expanded = Vec::new();
+    for &stmt in &block.imports {
+        let Stmt::ImportFrom(ast::StmtImportFrom {
+            module,
+            level,
+            names,
+            ..
+        }) = stmt
+        else {
+            continue;
+        };
+        let [alias] = names.as_slice() else {
+            continue;
+        };
+        if alias.name != "*" {
+            continue;
+        }
+
+        // Skip expansion when the target module can't be resolved, or isn't part of
+        // the analyzed project (third-party and stdlib modules aren't expanded).
+        let Some(target) =
+            resolver.resolve(module.as_deref(), level.unwrap_or(0), current_file)
+        else {
+            continue;
+        };
+        let Some(mut members) = public_surface(&target) else {
+            continue;
+        };
+
+        if !unresolved_names
+            .iter()
+            .all(|name| members.iter().any(|member| member == name))
+        {
+            continue;
+        }
+
+        members.sort();
+        members.dedup();
+        expanded.push(ExpandedGlobImport {
+            statement: stmt,
+            members,
+        });
+    }
+    expanded
+}
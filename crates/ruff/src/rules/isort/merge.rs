@@ -0,0 +1,376 @@
+use ruff_text_size::{TextRange, TextSize};
+use rustpython_parser::ast::{self, Ranged, Stmt};
+
+use ruff_python_ast::source_code::Locator;
+
+use crate::rules::isort::block::Block;
+
+/// The granularity at which otherwise-duplicate imports within a [`Block`] are merged
+/// together, mirroring rust-analyzer's `MergeBehaviour`.
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq)]
+pub enum MergeImports {
+    /// Leave every import statement exactly as written.
+    #[default]
+    Never,
+    /// Merge adjacent `Stmt::ImportFrom` statements that share a resolved module and
+    /// level, and adjacent `Stmt::Import` statements that share a dotted name,
+    /// regardless of aliasing. Statements with the same module that are separated by
+    /// another statement are not merged.
+    SameModule,
+    /// As [`MergeImports::SameModule`], but only merge statements that agree on the
+    /// alias of each name they share.
+    SameModuleAndAlias,
+}
+
+/// A single imported name, with its optional `as` alias.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct MergedMember<'a> {
+    pub(crate) name: &'a str,
+    pub(crate) asname: Option<&'a str>,
+}
+
+/// A run of `Stmt::ImportFrom` statements, collapsed down to the set of members they
+/// import between them.
+#[derive(Debug)]
+pub(crate) struct MergedImportFrom<'a> {
+    pub(crate) module: Option<&'a str>,
+    pub(crate) level: u32,
+    pub(crate) members: Vec<MergedMember<'a>>,
+    /// The statements that were folded together to produce this entry, in source
+    /// order. Only runs of two or more statements are recorded; a statement that
+    /// wasn't merged with anything has no entry.
+    pub(crate) statements: Vec<&'a Stmt>,
+}
+
+/// A run of `Stmt::Import` statements that share a dotted name, collapsed down to the
+/// set of aliases under which that name is bound between them (e.g. `import os` and
+/// `import os as o` merge into a single dotted name with two members).
+#[derive(Debug)]
+pub(crate) struct MergedImport<'a> {
+    pub(crate) name: &'a str,
+    pub(crate) members: Vec<MergedMember<'a>>,
+    pub(crate) statements: Vec<&'a Stmt>,
+}
+
+/// The result of merging duplicate imports within a single [`Block`].
+#[derive(Debug, Default)]
+pub(crate) struct MergedBlock<'a> {
+    pub(crate) import_froms: Vec<MergedImportFrom<'a>>,
+    pub(crate) imports: Vec<MergedImport<'a>>,
+}
+
+/// Merge the duplicate imports in `block` according to `merge_imports`.
+///
+/// This operates on a finalized [`Block`], so it naturally respects the split and
+/// exclusion boundaries that [`super::block::BlockBuilder`] already computed: imports
+/// that live in different blocks are never considered for merging. Statements that
+/// carry a leading or trailing comment are left untouched, since folding them into
+/// another statement would orphan the comment. `from ... import *` is never merged
+/// into (or out of) an explicit-member statement.
+pub(crate) fn merge<'a>(
+    block: &Block<'a>,
+    locator: &Locator,
+    merge_imports: MergeImports,
+) -> Option<MergedBlock<'a>> {
+    if merge_imports == MergeImports::Never {
+        return None;
+    }
+
+    // Opaque nested blocks are left alone, matching isort's own behavior. Guarded
+    // blocks (`TYPE_CHECKING`, `try`/`except`) and top-level blocks are both
+    // sortable, so both are eligible for merging.
+    if !block.nesting.is_sortable() {
+        return None;
+    }
+
+    let mut merged = MergedBlock::default();
+
+    // Group adjacent `ImportFrom` statements by `(module, level)`, stopping a run as
+    // soon as we hit a statement that can't safely join it.
+    let mut index = 0;
+    while index < block.imports.len() {
+        let stmt = block.imports[index];
+        match stmt {
+            Stmt::ImportFrom(ast::StmtImportFrom {
+                module,
+                level,
+                names,
+                ..
+            }) => {
+                // Never fold a star import into (or out of) an explicit-member
+                // statement.
+                if is_star_import(names) || has_orphanable_comment(stmt, locator) {
+                    index += 1;
+                    continue;
+                }
+
+                let module = module.as_deref();
+                let level = level.unwrap_or(0);
+                let mut statements = vec![stmt];
+                let mut run_end = index + 1;
+                while run_end < block.imports.len() {
+                    let Stmt::ImportFrom(ast::StmtImportFrom {
+                        module: next_module,
+                        level: next_level,
+                        names: next_names,
+                        ..
+                    }) = block.imports[run_end]
+                    else {
+                        break;
+                    };
+                    if next_module.as_deref() != module
+                        || next_level.unwrap_or(0) != level
+                        || is_star_import(next_names)
+                        || has_orphanable_comment(block.imports[run_end], locator)
+                    {
+                        break;
+                    }
+                    // Under `SameModuleAndAlias`, a name already in the run under a
+                    // different alias ends the run.
+                    if merge_imports == MergeImports::SameModuleAndAlias
+                        && next_names.iter().any(|next| {
+                            statements.iter().filter_map(|stmt| aliases(stmt)).flatten().any(
+                                |alias| alias.name == next.name && alias.asname != next.asname,
+                            )
+                        })
+                    {
+                        break;
+                    }
+                    statements.push(block.imports[run_end]);
+                    run_end += 1;
+                }
+
+                if statements.len() > 1 {
+                    let members = merge_members(&statements);
+                    merged.import_froms.push(MergedImportFrom {
+                        module,
+                        level,
+                        members,
+                        statements,
+                    });
+                }
+                index = run_end;
+            }
+            Stmt::Import(_) => {
+                index += 1;
+            }
+            _ => index += 1,
+        }
+    }
+
+    // Group adjacent plain `import` statements that share a dotted name.
+    let mut index = 0;
+    while index < block.imports.len() {
+        let Stmt::Import(ast::StmtImport { names, .. }) = block.imports[index] else {
+            index += 1;
+            continue;
+        };
+        if names.len() != 1 || has_orphanable_comment(block.imports[index], locator) {
+            index += 1;
+            continue;
+        }
+        let alias = &names[0];
+        let mut statements = vec![block.imports[index]];
+        let mut run_end = index + 1;
+        while run_end < block.imports.len() {
+            let Stmt::Import(ast::StmtImport { names, .. }) = block.imports[run_end] else {
+                break;
+            };
+            if names.len() != 1 || has_orphanable_comment(block.imports[run_end], locator) {
+                break;
+            }
+            let next_alias = &names[0];
+            if next_alias.name != alias.name {
+                break;
+            }
+            if merge_imports == MergeImports::SameModuleAndAlias
+                && next_alias.asname != alias.asname
+            {
+                break;
+            }
+            statements.push(block.imports[run_end]);
+            run_end += 1;
+        }
+        if statements.len() > 1 {
+            merged.imports.push(MergedImport {
+                name: &alias.name,
+                members: merge_members(&statements),
+                statements,
+            });
+        }
+        index = run_end;
+    }
+
+    if merged.import_froms.is_empty() && merged.imports.is_empty() {
+        None
+    } else {
+        Some(merged)
+    }
+}
+
+/// Union the imported members of `statements`, dropping only exact `(name, asname)`
+/// duplicates. A name seen under two different aliases (`import b` alongside `import
+/// b as c`) is never collapsed: each alias binds a distinct name elsewhere in the
+/// module, so dropping either one would be a behavior change, not a dedup. Caller is
+/// responsible for re-sorting the result with the existing isort ordering before
+/// rendering it.
+fn merge_members<'a>(statements: &[&'a Stmt]) -> Vec<MergedMember<'a>> {
+    let mut members: Vec<MergedMember<'a>> = Vec::new();
+    for stmt in statements {
+        let Some(names) = aliases(stmt) else {
+            continue;
+        };
+        for alias in names {
+            let member = MergedMember {
+                name: &alias.name,
+                asname: alias.asname.as_deref(),
+            };
+            if !members.contains(&member) {
+                members.push(member);
+            }
+        }
+    }
+    members
+}
+
+/// The imported names of an `import` or `from ... import` statement.
+fn aliases(stmt: &Stmt) -> Option<&[ast::Alias]> {
+    match stmt {
+        Stmt::ImportFrom(ast::StmtImportFrom { names, .. })
+        | Stmt::Import(ast::StmtImport { names, .. }) => Some(names),
+        _ => None,
+    }
+}
+
+fn is_star_import(names: &[ast::Alias]) -> bool {
+    matches!(names, [alias] if &alias.name == "*")
+}
+
+/// Whether `stmt` has a leading or trailing comment that would be orphaned if the
+/// statement were folded into a neighboring one.
+fn has_orphanable_comment(stmt: &Stmt, locator: &Locator) -> bool {
+    // A trailing comment on the statement's own line, e.g. `from a import b  # noqa`.
+    let line_end = locator.line_end(stmt.end());
+    let suffix = locator.slice(TextRange::new(stmt.end(), line_end));
+    if suffix.contains('#') {
+        return true;
+    }
+
+    // A standalone comment line directly above the statement, with no blank line in
+    // between (a blank line would instead associate the comment with whatever came
+    // before it), e.g.:
+    //
+    //     from a import b
+    //     # explains c
+    //     from a import c
+    let line_start = locator.line_start(stmt.start());
+    if line_start > TextSize::from(0) {
+        let previous_line_end = line_start - TextSize::from(1);
+        let previous_line_start = locator.line_start(previous_line_end);
+        let previous_line = locator.slice(TextRange::new(previous_line_start, previous_line_end));
+        if previous_line.trim().starts_with('#') {
+            return true;
+        }
+    }
+
+    false
+}
+
+#[cfg(test)]
+mod tests {
+    use ruff_python_ast::source_code::Locator;
+    use rustpython_parser::ast::Stmt;
+
+    use super::*;
+
+    fn import_froms(source: &str) -> Vec<Stmt> {
+        rustpython_parser::parse_program(source, "<filename>").unwrap()
+    }
+
+    #[test]
+    fn merge_members_preserves_distinct_aliases_for_the_same_name() {
+        let stmts = import_froms("from a import b\nfrom a import b as c\n");
+        let statements: Vec<&Stmt> = stmts.iter().collect();
+        let members = merge_members(&statements);
+        assert_eq!(
+            members,
+            vec![
+                MergedMember {
+                    name: "b",
+                    asname: None
+                },
+                MergedMember {
+                    name: "b",
+                    asname: Some("c")
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn merge_members_drops_exact_duplicates() {
+        let stmts = import_froms("from a import b\nfrom a import b\n");
+        let statements: Vec<&Stmt> = stmts.iter().collect();
+        let members = merge_members(&statements);
+        assert_eq!(
+            members,
+            vec![MergedMember {
+                name: "b",
+                asname: None
+            }]
+        );
+    }
+
+    #[test]
+    fn merge_preserves_distinct_aliases_for_plain_imports() {
+        use crate::rules::isort::block::Block;
+
+        let source = "import os\nimport os as o\n";
+        let stmts = import_froms(source);
+        let locator = Locator::new(source);
+        let mut block = Block::default();
+        block.imports = stmts.iter().collect();
+
+        let merged = merge(&block, &locator, MergeImports::SameModule).expect("expected a merge");
+        assert_eq!(merged.imports.len(), 1);
+        assert_eq!(
+            merged.imports[0].members,
+            vec![
+                MergedMember {
+                    name: "os",
+                    asname: None
+                },
+                MergedMember {
+                    name: "os",
+                    asname: Some("o")
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn has_orphanable_comment_detects_a_standalone_leading_comment_line() {
+        let source = "from a import b\n# explains c\nfrom a import c\n";
+        let locator = Locator::new(source);
+        let stmts = import_froms(source);
+        assert!(!has_orphanable_comment(&stmts[0], &locator));
+        assert!(has_orphanable_comment(&stmts[1], &locator));
+    }
+
+    #[test]
+    fn has_orphanable_comment_detects_a_trailing_comment() {
+        let source = "from a import b  # noqa\n";
+        let locator = Locator::new(source);
+        let stmts = import_froms(source);
+        assert!(has_orphanable_comment(&stmts[0], &locator));
+    }
+
+    #[test]
+    fn has_orphanable_comment_ignores_a_clean_import() {
+        let source = "from a import b\nfrom a import c\n";
+        let locator = Locator::new(source);
+        let stmts = import_froms(source);
+        assert!(!has_orphanable_comment(&stmts[0], &locator));
+        assert!(!has_orphanable_comment(&stmts[1], &locator));
+    }
+}
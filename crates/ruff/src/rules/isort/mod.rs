@@ -0,0 +1,186 @@
+use std::path::Path;
+
+use ruff_python_ast::source_code::Locator;
+use ruff_python_ast::statement_visitor::StatementVisitor;
+
+use crate::directives::IsortDirectives;
+use crate::rules::isort::block::{Block, BlockBuilder};
+use crate::rules::isort::expand::ExpandedGlobImport;
+use crate::rules::isort::merge::MergedBlock;
+use crate::rules::isort::resolve::ModuleResolver;
+use crate::rules::isort::settings::Settings;
+use crate::rules::isort::split::SplitOutcome;
+
+pub(crate) mod block;
+pub(crate) mod expand;
+pub(crate) mod merge;
+pub(crate) mod resolve;
+pub(crate) mod settings;
+pub(crate) mod split;
+
+/// How many of each transform pass fired while processing a file's import blocks.
+///
+/// `BlockBuilder` (and the `Block`s, `MergedBlock`s, etc. it and the passes below
+/// produce) all borrow from that file's parsed AST, so they can't outlive
+/// [`transform_imports`]. This summary is the owned result that does outlive it; a
+/// renderer that turns it back into fixed source text is the next seam in this
+/// pipeline, once ruff's fix-generation machinery is plugged in here.
+#[derive(Debug, Default, PartialEq, Eq)]
+pub(crate) struct IsortTransformSummary {
+    pub(crate) merged_import_froms: usize,
+    pub(crate) merged_imports: usize,
+    pub(crate) expanded_star_imports: usize,
+    pub(crate) split_import_froms: usize,
+}
+
+/// Parse `source`, collect its import blocks with [`BlockBuilder`], and run every
+/// enabled transform pass (merge, glob-expansion, split) over each one, as configured
+/// by `settings`.
+///
+/// This is the single production entry point for the three passes below: nothing
+/// outside of it should call `merge::merge`, `expand::expand_glob_imports`, or
+/// `split::split_import_froms` directly.
+///
+/// `unresolved_names` is the set of names referenced in `current_file` that aren't
+/// bound by anything else, and so must come from one of its glob imports; see
+/// [`expand::expand_glob_imports`] for why that's required for a safe expansion.
+pub(crate) fn transform_imports(
+    source: &str,
+    current_file: &Path,
+    unresolved_names: &[String],
+    is_stub: bool,
+    settings: &Settings,
+) -> IsortTransformSummary {
+    let mut summary = IsortTransformSummary::default();
+
+    let Ok(suite) = rustpython_parser::parse_program(source, &current_file.to_string_lossy())
+    else {
+        return summary;
+    };
+
+    let locator = Locator::new(source);
+    let directives = IsortDirectives::default();
+    let mut builder = BlockBuilder::new(&locator, &directives, is_stub);
+    for stmt in &suite {
+        builder.visit_stmt(stmt);
+    }
+
+    for block in builder.iter() {
+        if let Some(merged) = merged_imports_for_block(block, &locator, settings) {
+            summary.merged_import_froms += merged.import_froms.len();
+            summary.merged_imports += merged.imports.len();
+        }
+
+        summary.expanded_star_imports +=
+            expanded_glob_imports_for_block(block, current_file, unresolved_names, settings).len();
+
+        summary.split_import_froms += split_import_froms_for_block(block, &locator, settings)
+            .iter()
+            .filter(|outcome| matches!(outcome, SplitOutcome::Split(_)))
+            .count();
+    }
+
+    summary
+}
+
+/// Merge the duplicate imports in `block`, as configured by `settings.merge_imports`.
+/// This is the seam between `BlockBuilder`'s raw import collection and the
+/// sorter/renderer: merging only changes which statements a block's imports resolve
+/// to, never how they're subsequently ordered.
+pub(crate) fn merged_imports_for_block<'a>(
+    block: &Block<'a>,
+    locator: &Locator,
+    settings: &Settings,
+) -> Option<MergedBlock<'a>> {
+    merge::merge(block, locator, settings.merge_imports)
+}
+
+/// Expand the `from module import *` statements in `block`, as configured by
+/// `settings.expand_star_imports` and `settings.src`.
+///
+/// `unresolved_names` is the set of names referenced in `current_file` that aren't
+/// bound by anything else, and so must come from one of its glob imports; see
+/// [`expand::expand_glob_imports`] for why that's required for a safe expansion.
+pub(crate) fn expanded_glob_imports_for_block<'a>(
+    block: &Block<'a>,
+    current_file: &Path,
+    unresolved_names: &[String],
+    settings: &Settings,
+) -> Vec<ExpandedGlobImport<'a>> {
+    let resolver = ModuleResolver::new(&settings.src);
+    expand::expand_glob_imports(
+        block,
+        current_file,
+        &resolver,
+        unresolved_names,
+        settings.expand_star_imports,
+    )
+}
+
+/// Split the combined `from module import a, b, c` statements in `block` into one
+/// `Stmt::ImportFrom` per name, as configured by `settings.split_imports`.
+pub(crate) fn split_import_froms_for_block<'a>(
+    block: &Block<'a>,
+    locator: &Locator,
+    settings: &Settings,
+) -> Vec<SplitOutcome<'a>> {
+    split::split_import_froms(block, locator, settings.split_imports)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::Path;
+
+    use super::*;
+
+    #[test]
+    fn transform_imports_runs_the_merge_pass() {
+        let settings = Settings {
+            merge_imports: crate::rules::isort::merge::MergeImports::SameModule,
+            ..Settings::default()
+        };
+        let summary = transform_imports(
+            "from a import b\nfrom a import c\n",
+            Path::new("module.py"),
+            &[],
+            false,
+            &settings,
+        );
+        assert_eq!(
+            summary,
+            IsortTransformSummary {
+                merged_import_froms: 1,
+                ..IsortTransformSummary::default()
+            }
+        );
+    }
+
+    #[test]
+    fn transform_imports_runs_the_split_pass() {
+        let settings = Settings {
+            split_imports: true,
+            ..Settings::default()
+        };
+        let summary = transform_imports(
+            "from a import b, c\n",
+            Path::new("module.py"),
+            &[],
+            false,
+            &settings,
+        );
+        assert_eq!(
+            summary,
+            IsortTransformSummary {
+                split_import_froms: 1,
+                ..IsortTransformSummary::default()
+            }
+        );
+    }
+
+    #[test]
+    fn transform_imports_returns_an_empty_summary_for_unparseable_source() {
+        let settings = Settings::default();
+        let summary = transform_imports("def(\n", Path::new("module.py"), &[], false, &settings);
+        assert_eq!(summary, IsortTransformSummary::default());
+    }
+}
@@ -0,0 +1,298 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use rustpython_parser::ast::{self, Constant, Expr, Stmt, Suite};
+
+/// Resolves a dotted module path (honoring relative-import `level`) to a source file
+/// within the project's first-party `src` roots, so that
+/// [`crate::rules::isort::expand`] can inline a `from module import *` into its
+/// expanded member list.
+pub(crate) struct ModuleResolver<'a> {
+    src_roots: &'a [PathBuf],
+}
+
+impl<'a> ModuleResolver<'a> {
+    pub(crate) fn new(src_roots: &'a [PathBuf]) -> Self {
+        Self { src_roots }
+    }
+
+    /// Resolve `module` (dotted, e.g. `"a.b"`) at relative-import `level` (`0` for an
+    /// absolute import) as seen from `current_file`, returning the `.py` file it
+    /// corresponds to, if any first-party root contains it.
+    pub(crate) fn resolve(
+        &self,
+        module: Option<&str>,
+        level: u32,
+        current_file: &Path,
+    ) -> Option<PathBuf> {
+        let relative = module.map(|module| module.replace('.', "/"));
+
+        if level > 0 {
+            // `level` counts the leading dots: one dot means "the current package".
+            let mut base = current_file.parent()?.to_path_buf();
+            for _ in 1..level {
+                base = base.parent()?.to_path_buf();
+            }
+            return self.to_module_file(&base, relative.as_deref());
+        }
+
+        for root in self.src_roots {
+            if let Some(path) = self.to_module_file(root, relative.as_deref()) {
+                return Some(path);
+            }
+        }
+        None
+    }
+
+    fn to_module_file(&self, base: &Path, relative: Option<&str>) -> Option<PathBuf> {
+        let dir = match relative {
+            Some(relative) if !relative.is_empty() => base.join(relative),
+            _ => base.to_path_buf(),
+        };
+
+        let as_module = dir.with_extension("py");
+        if as_module.is_file() {
+            return Some(as_module);
+        }
+
+        let as_package = dir.join("__init__.py");
+        if as_package.is_file() {
+            return Some(as_package);
+        }
+
+        None
+    }
+}
+
+/// The set of names that `path` exposes to `from module import *`: its `__all__`
+/// list, when present, otherwise every module-scope binding that isn't
+/// `_`-prefixed.
+pub(crate) fn public_surface(path: &Path) -> Option<Vec<String>> {
+    let source = fs::read_to_string(path).ok()?;
+    let suite: Suite = rustpython_parser::parse_program(&source, &path.to_string_lossy()).ok()?;
+
+    match find_dunder_all(&suite) {
+        // `__all__` is present and we could make sense of it: that's the surface.
+        DunderAll::Present(names) => return Some(names),
+        // `__all__` is present but not something we can evaluate statically (e.g. a
+        // name reference or a computed value): we can't tell what it actually
+        // restricts, so don't fall back to "every binding" and risk exposing names
+        // `__all__` would have excluded.
+        DunderAll::Unparseable => return None,
+        DunderAll::Absent => {}
+    }
+
+    let mut names: Vec<String> = suite
+        .iter()
+        .flat_map(bound_names)
+        .filter(|name| !name.starts_with('_'))
+        .collect();
+    names.sort();
+    names.dedup();
+    Some(names)
+}
+
+/// The result of looking for a module-level `__all__` assignment.
+enum DunderAll {
+    /// No `__all__` assignment exists.
+    Absent,
+    /// `__all__` exists and evaluated to an explicit list of names.
+    Present(Vec<String>),
+    /// `__all__` exists, but isn't a literal `list`/`tuple` of string constants we can
+    /// evaluate statically.
+    Unparseable,
+}
+
+/// The names a top-level import, function or class definition, or assignment to plain
+/// names binds at module scope.
+///
+/// A single statement can bind more than one name (`import a, b`, `from a import b,
+/// c`, `x = y = 1`), so this returns one entry per binding rather than just the first.
+/// Tuple-unpacking targets, `for`/`with` targets, bindings inside compound statements
+/// and the names brought in by a `from ... import *` are not collected.
+fn bound_names(stmt: &Stmt) -> Vec<String> {
+    match stmt {
+        Stmt::FunctionDef(ast::StmtFunctionDef { name, .. })
+        | Stmt::AsyncFunctionDef(ast::StmtAsyncFunctionDef { name, .. })
+        | Stmt::ClassDef(ast::StmtClassDef { name, .. }) => vec![name.to_string()],
+        Stmt::Assign(ast::StmtAssign { targets, .. }) => {
+            targets.iter().filter_map(simple_target).collect()
+        }
+        Stmt::AnnAssign(ast::StmtAnnAssign { target, .. }) => {
+            simple_target(target).into_iter().collect()
+        }
+        Stmt::Import(ast::StmtImport { names, .. }) => names
+            .iter()
+            .map(|alias| {
+                alias
+                    .asname
+                    .as_deref()
+                    .unwrap_or_else(|| alias.name.split('.').next().unwrap_or(&alias.name))
+                    .to_string()
+            })
+            .collect(),
+        Stmt::ImportFrom(ast::StmtImportFrom { names, .. }) => names
+            .iter()
+            .filter(|alias| alias.name != "*")
+            .map(|alias| alias.asname.as_deref().unwrap_or(&alias.name).to_string())
+            .collect(),
+        _ => Vec::new(),
+    }
+}
+
+fn simple_target(expr: &Expr) -> Option<String> {
+    if let Expr::Name(ast::ExprName { id, .. }) = expr {
+        Some(id.to_string())
+    } else {
+        None
+    }
+}
+
+/// Find and evaluate a module-level `__all__ = [...]` (or `(...)`) assignment, however
+/// it's spelled (`__all__ = [...]` or the annotated `__all__: list = [...]`).
+fn find_dunder_all(body: &[Stmt]) -> DunderAll {
+    let value = body.iter().find_map(|stmt| match stmt {
+        Stmt::Assign(ast::StmtAssign { targets, value, .. })
+            if targets.iter().any(|target| is_dunder_all_name(target)) =>
+        {
+            Some(value.as_ref())
+        }
+        Stmt::AnnAssign(ast::StmtAnnAssign {
+            target,
+            value: Some(value),
+            ..
+        }) if is_dunder_all_name(target) => Some(value.as_ref()),
+        _ => None,
+    });
+
+    let Some(value) = value else {
+        return DunderAll::Absent;
+    };
+
+    // Only a literal list/tuple of string constants can be evaluated statically. A
+    // name reference, a computed expression, or anything else means we genuinely
+    // don't know what `__all__` restricts, so the caller must not fall back to
+    // treating every binding as public.
+    match value {
+        Expr::List(ast::ExprList { elts, .. }) | Expr::Tuple(ast::ExprTuple { elts, .. }) => {
+            match elts.iter().map(string_literal).collect::<Option<Vec<_>>>() {
+                Some(names) => DunderAll::Present(names),
+                None => DunderAll::Unparseable,
+            }
+        }
+        _ => DunderAll::Unparseable,
+    }
+}
+
+fn is_dunder_all_name(expr: &Expr) -> bool {
+    matches!(expr, Expr::Name(ast::ExprName { id, .. }) if id == "__all__")
+}
+
+fn string_literal(expr: &Expr) -> Option<String> {
+    if let Expr::Constant(ast::ExprConstant {
+        value: Constant::Str(value),
+        ..
+    }) = expr
+    {
+        Some(value.clone())
+    } else {
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn bound_names_in(source: &str) -> Vec<String> {
+        let suite = rustpython_parser::parse_program(source, "<filename>").unwrap();
+        suite.iter().flat_map(bound_names).collect()
+    }
+
+    #[test]
+    fn import_from_binds_every_name_not_just_the_first() {
+        let names = bound_names_in("from a import foo, bar, baz\n");
+        assert_eq!(names, vec!["foo", "bar", "baz"]);
+    }
+
+    #[test]
+    fn import_from_respects_aliases() {
+        let names = bound_names_in("from a import foo as f, bar\n");
+        assert_eq!(names, vec!["f", "bar"]);
+    }
+
+    #[test]
+    fn import_binds_every_dotted_name_not_just_the_first() {
+        let names = bound_names_in("import foo.bar, baz\n");
+        assert_eq!(names, vec!["foo", "baz"]);
+    }
+
+    #[test]
+    fn assignment_binds_every_target() {
+        let names = bound_names_in("x = y = 1\n");
+        assert_eq!(names, vec!["x", "y"]);
+    }
+
+    #[test]
+    fn function_and_class_defs_bind_their_name() {
+        let names = bound_names_in("def f():\n    pass\nclass C:\n    pass\n");
+        assert_eq!(names, vec!["f", "C"]);
+    }
+
+    fn dunder_all_in(source: &str) -> DunderAll {
+        let suite = rustpython_parser::parse_program(source, "<filename>").unwrap();
+        find_dunder_all(&suite)
+    }
+
+    #[test]
+    fn dunder_all_parses_a_literal_list() {
+        assert!(matches!(
+            dunder_all_in("__all__ = [\"a\", \"b\"]\n"),
+            DunderAll::Present(names) if names == vec!["a", "b"]
+        ));
+    }
+
+    #[test]
+    fn dunder_all_parses_an_annotated_literal_list() {
+        assert!(matches!(
+            dunder_all_in("__all__: list = [\"a\"]\n"),
+            DunderAll::Present(names) if names == vec!["a"]
+        ));
+    }
+
+    #[test]
+    fn dunder_all_is_absent_when_not_assigned() {
+        assert!(matches!(dunder_all_in("x = 1\n"), DunderAll::Absent));
+    }
+
+    #[test]
+    fn dunder_all_is_unparseable_when_it_references_a_name() {
+        assert!(matches!(
+            dunder_all_in("__all__ = PUBLIC_NAMES\n"),
+            DunderAll::Unparseable
+        ));
+    }
+
+    #[test]
+    fn dunder_all_is_unparseable_when_an_element_is_not_a_string_literal() {
+        assert!(matches!(
+            dunder_all_in("__all__ = [\"a\", SOME_VAR]\n"),
+            DunderAll::Unparseable
+        ));
+    }
+
+    #[test]
+    fn public_surface_returns_none_for_an_unparseable_dunder_all() {
+        let dir = std::env::temp_dir().join(format!(
+            "ruff-isort-resolve-test-{:?}",
+            std::thread::current().id()
+        ));
+        std::fs::create_dir_all(&dir).unwrap();
+        let path = dir.join("module.py");
+        std::fs::write(&path, "__all__ = PUBLIC_NAMES\n\ndef foo():\n    pass\n").unwrap();
+
+        assert_eq!(public_surface(&path), None);
+
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+}
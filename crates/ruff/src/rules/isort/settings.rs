@@ -0,0 +1,32 @@
+use std::path::PathBuf;
+
+use crate::rules::isort::merge::MergeImports;
+
+/// Settings for the `isort` rules (`I001`, ...).
+#[derive(Debug, Clone)]
+pub struct Settings {
+    /// Whether (and how granularly) to merge otherwise-duplicate imports within a
+    /// block before sorting it. See [`MergeImports`].
+    pub merge_imports: MergeImports,
+    /// Whether to expand `from module import *` into an explicit, sorted member list
+    /// when the target module resolves to a file inside the project. Off by default,
+    /// since it requires resolving and parsing other files in the project.
+    pub expand_star_imports: bool,
+    /// The first-party source roots used to resolve project-local imports to a file
+    /// on disk, e.g. when `expand_star_imports` is enabled.
+    pub src: Vec<PathBuf>,
+    /// Whether to split a combined `from module import a, b, c` into one import
+    /// statement per name before formatting.
+    pub split_imports: bool,
+}
+
+impl Default for Settings {
+    fn default() -> Self {
+        Self {
+            merge_imports: MergeImports::default(),
+            expand_star_imports: false,
+            src: vec![PathBuf::from(".")],
+            split_imports: false,
+        }
+    }
+}
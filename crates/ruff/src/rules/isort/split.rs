@@ -0,0 +1,177 @@
+use ruff_text_size::TextRange;
+use rustpython_parser::ast::{self, Ranged, Stmt};
+
+use ruff_python_ast::source_code::Locator;
+
+use crate::rules::isort::block::Block;
+
+/// A single name pulled out of a combined `from ... import a, b, c` statement.
+#[derive(Debug)]
+pub(crate) struct SplitMember<'a> {
+    pub(crate) module: Option<&'a str>,
+    pub(crate) level: u32,
+    pub(crate) name: &'a str,
+    pub(crate) asname: Option<&'a str>,
+    /// An inline comment that unambiguously belongs to this member (i.e. one that
+    /// appears between this name and the next), carried along so it rides with the
+    /// generated single-name statement rather than being dropped.
+    pub(crate) trailing_comment: Option<String>,
+}
+
+/// The outcome of attempting to split a single `Stmt::ImportFrom`.
+#[derive(Debug)]
+pub(crate) enum SplitOutcome<'a> {
+    /// The statement was decomposed into one entry per imported name.
+    Split(Vec<SplitMember<'a>>),
+    /// The statement was left as-is, because a comment on it couldn't be
+    /// unambiguously attributed to one name. Statements that don't need splitting
+    /// (fewer than two members, or a bare `from a import *`) produce no outcome at all.
+    Unsplit(&'a Stmt),
+}
+
+/// Split every combined `from ... import a, b, c` in `block` into one
+/// `Stmt::ImportFrom` per name, mirroring rust-analyzer's `split_import` assist.
+///
+/// Doing this at the [`Block`] level, rather than string-splitting the rendered
+/// import, lets a trailing comment that applies to a specific member ride along with
+/// that member's generated statement. `from a import *` is never split: a star import
+/// has exactly one target already. When a statement carries a comment that can't be
+/// unambiguously attributed to a single name (e.g. a trailing `# noqa` on a one-line,
+/// multi-name import), it's left unsplit and a warning is logged rather than guessed
+/// at.
+pub(crate) fn split_import_froms<'a>(
+    block: &Block<'a>,
+    locator: &Locator,
+    enabled: bool,
+) -> Vec<SplitOutcome<'a>> {
+    // Like the merge and glob-expansion passes, this only touches blocks isort would
+    // otherwise sort: top-level blocks and guarded (`TYPE_CHECKING`, `try`/`except`)
+    // blocks. Opaque nested blocks (a plain function body, a `for`/`with` suite, ...)
+    // are left exactly as written.
+    if !enabled || !block.nesting.is_sortable() {
+        return Vec::new();
+    }
+
+    block
+        .imports
+        .iter()
+        .filter_map(|&stmt| {
+            let Stmt::ImportFrom(ast::StmtImportFrom {
+                module,
+                level,
+                names,
+                ..
+            }) = stmt
+            else {
+                return None;
+            };
+
+            if names.len() < 2 || matches!(names.as_slice(), [alias] if alias.name == "*") {
+                return None;
+            }
+
+            if has_ambiguous_trailing_comment(stmt, names, locator) {
+                log::warn!(
+                    "Leaving `{}` unsplit: a trailing comment can't be attributed to a single name",
+                    locator.slice(stmt.range()),
+                );
+                return Some(SplitOutcome::Unsplit(stmt));
+            }
+
+            let module = module.as_deref();
+            let level = level.unwrap_or(0);
+            let members = names
+                .iter()
+                .enumerate()
+                .map(|(index, alias)| SplitMember {
+                    module,
+                    level,
+                    name: &alias.name,
+                    asname: alias.asname.as_deref(),
+                    trailing_comment: member_comment(names, index, locator),
+                })
+                .collect();
+            Some(SplitOutcome::Split(members))
+        })
+        .collect()
+}
+
+/// The inline comment that immediately follows the name at `index`, if any, as long as
+/// it appears before the next name starts (and so unambiguously belongs to this
+/// member rather than to the statement as a whole).
+fn member_comment(names: &[ast::Alias], index: usize, locator: &Locator) -> Option<String> {
+    let next_start = names.get(index + 1)?.start();
+    let between = locator.slice(TextRange::new(names[index].end(), next_start));
+    let (_, comment) = between.split_once('#')?;
+    Some(format!("#{comment}").trim_end().to_string())
+}
+
+/// Whether `stmt` has a trailing comment after its *last* imported name that can't be
+/// attributed to that name specifically, because it could just as easily be a comment
+/// on the statement as a whole (e.g. `from a import b, c  # noqa`).
+///
+/// `stmt`'s own range never extends past its last token (the closing `)` for a
+/// parenthesized import, or the last name otherwise), so a same-line trailing comment
+/// sits *after* `stmt.end()`. Look from the last name all the way out to the end of
+/// that physical line, the way `merge.rs::has_orphanable_comment` does, rather than
+/// stopping at `stmt.end()`; this also covers a comment after the last name inside
+/// the parentheses.
+fn has_ambiguous_trailing_comment(stmt: &Stmt, names: &[ast::Alias], locator: &Locator) -> bool {
+    let Some(last) = names.last() else {
+        return false;
+    };
+    let line_end = locator.line_end(stmt.end());
+    let between = locator.slice(TextRange::new(last.end(), line_end));
+    between.contains('#')
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn import_from(source: &str) -> (Stmt, Locator) {
+        let locator = Locator::new(source);
+        let mut program = rustpython_parser::parse_program(source, "<filename>").unwrap();
+        (program.remove(0), locator)
+    }
+
+    #[test]
+    fn member_comment_attributes_an_inline_comment_to_the_preceding_name() {
+        let (stmt, locator) = import_from("from a import (\n    b,  # about b\n    c,\n)\n");
+        let Stmt::ImportFrom(ast::StmtImportFrom { names, .. }) = &stmt else {
+            panic!("expected a `from` import");
+        };
+        assert_eq!(
+            member_comment(names, 0, &locator).as_deref(),
+            Some("# about b")
+        );
+        assert_eq!(member_comment(names, 1, &locator), None);
+    }
+
+    #[test]
+    fn has_ambiguous_trailing_comment_flags_a_one_line_multi_name_import() {
+        let (stmt, locator) = import_from("from a import b, c  # noqa\n");
+        let Stmt::ImportFrom(ast::StmtImportFrom { names, .. }) = &stmt else {
+            panic!("expected a `from` import");
+        };
+        assert!(has_ambiguous_trailing_comment(&stmt, names, &locator));
+    }
+
+    #[test]
+    fn has_ambiguous_trailing_comment_flags_a_parenthesized_multi_name_import() {
+        let (stmt, locator) = import_from("from a import (\n    b,\n    c,\n)  # noqa\n");
+        let Stmt::ImportFrom(ast::StmtImportFrom { names, .. }) = &stmt else {
+            panic!("expected a `from` import");
+        };
+        assert!(has_ambiguous_trailing_comment(&stmt, names, &locator));
+    }
+
+    #[test]
+    fn has_ambiguous_trailing_comment_ignores_a_clean_import() {
+        let (stmt, locator) = import_from("from a import b, c\n");
+        let Stmt::ImportFrom(ast::StmtImportFrom { names, .. }) = &stmt else {
+            panic!("expected a `from` import");
+        };
+        assert!(!has_ambiguous_trailing_comment(&stmt, names, &locator));
+    }
+}